@@ -0,0 +1,145 @@
+// Derive macros that auto-delegate the capability traits (`Position`,
+// `Health`, `Combatant`) to a `#[component]`-annotated field, so entity
+// structs don't have to hand-write the same forwarding impl every time.
+//
+// Each derive finds the field whose type matches the component it forwards
+// to (by the type's last path segment) among the fields marked `#[component]`,
+// and errors out with a clear message if no such field exists.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, parse_macro_input};
+
+fn is_component_field(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|attr| attr.path().is_ident("component"))
+}
+
+fn type_last_segment(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+// Finds the `#[component]` field whose type is `component_type_name`, e.g.
+// `find_component_field(&ast, "PositionComponent")`.
+fn find_component_field<'a>(
+    ast: &'a DeriveInput,
+    component_type_name: &str,
+) -> Result<&'a syn::Field, syn::Error> {
+    let Data::Struct(data) = &ast.data else {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "this derive only supports structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "this derive requires named fields",
+        ));
+    };
+
+    fields
+        .named
+        .iter()
+        .find(|field| {
+            is_component_field(field) && type_last_segment(&field.ty).as_deref() == Some(component_type_name)
+        })
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                ast,
+                format!(
+                    "expected a `#[component]` field of type `{component_type_name}`",
+                ),
+            )
+        })
+}
+
+#[proc_macro_derive(Position, attributes(component))]
+pub fn derive_position(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let name = &ast.ident;
+
+    let field = match find_component_field(&ast, "PositionComponent") {
+        Ok(field) => field,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let field_ident = field.ident.as_ref().unwrap();
+
+    quote! {
+        impl Position for #name {
+            fn get_position(&self) -> (f32, f32) {
+                (self.#field_ident.x, self.#field_ident.y)
+            }
+
+            fn set_position(&mut self, x: f32, y: f32) {
+                self.#field_ident.x = x;
+                self.#field_ident.y = y;
+            }
+        }
+    }
+    .into()
+}
+
+#[proc_macro_derive(Health, attributes(component))]
+pub fn derive_health(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let name = &ast.ident;
+
+    let field = match find_component_field(&ast, "HealthComponent") {
+        Ok(field) => field,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let field_ident = field.ident.as_ref().unwrap();
+
+    quote! {
+        impl Health for #name {
+            fn get_health(&self) -> i32 {
+                self.#field_ident.current
+            }
+
+            fn set_health(&mut self, health: i32) {
+                self.#field_ident.current = health.min(self.#field_ident.max);
+            }
+
+            fn get_physical_resistance(&self) -> f32 {
+                self.#field_ident.physical_resistance
+            }
+
+            fn get_magic_resistance(&self) -> f32 {
+                self.#field_ident.magic_resistance
+            }
+
+            fn armor(&self) -> f32 {
+                self.#field_ident.armor
+            }
+        }
+    }
+    .into()
+}
+
+#[proc_macro_derive(Combatant, attributes(component))]
+pub fn derive_combatant(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let name = &ast.ident;
+
+    let field = match find_component_field(&ast, "CombatComponent") {
+        Ok(field) => field,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let field_ident = field.ident.as_ref().unwrap();
+
+    quote! {
+        impl Combatant for #name {
+            fn get_attack_damage(&self) -> Damage {
+                self.#field_ident.damage
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,80 @@
+// =============================================================================
+// PART 8: Data-Driven Entity Definitions
+// =============================================================================
+//
+// Entity stats no longer have to be hardcoded in `Enemy::new`/`FlyingEnemy::new`
+// call sites: a `ContentRegistry` loads named archetypes from a TOML file and
+// `spawn` builds whichever concrete type the archetype calls for, adding a
+// `FlyingComponent` only when the archetype has a `flying` table.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{Entity, Enemy, Faction, FlyingEnemy};
+
+#[derive(Debug, Deserialize)]
+struct FlyingArchetype {
+    max_altitude: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct EntityArchetype {
+    hull: i32,
+    damage: f32,
+    flying: Option<FlyingArchetype>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContentFile {
+    entity: HashMap<String, EntityArchetype>,
+}
+
+/// Entity archetypes loaded from a TOML content file, keyed by name (e.g.
+/// `[entity."dragon"]`). Lets designers add new enemy types by editing the
+/// file instead of recompiling.
+#[derive(Debug, Default)]
+pub struct ContentRegistry {
+    archetypes: HashMap<String, EntityArchetype>,
+}
+
+impl ContentRegistry {
+    pub fn load_str(toml_source: &str) -> Result<Self, toml::de::Error> {
+        let file: ContentFile = toml::from_str(toml_source)?;
+        Ok(Self {
+            archetypes: file.entity,
+        })
+    }
+
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        Self::load_str(&contents).map_err(|err| format!("failed to parse {}: {err}", path.display()))
+    }
+
+    /// Builds the archetype named `name` at `(x, y)`, spawning a
+    /// `FlyingEnemy` when the archetype has a `flying` table and a plain
+    /// `Enemy` otherwise. Returns `None` if no archetype is registered under
+    /// that name.
+    pub fn spawn(&self, name: &str, x: f32, y: f32) -> Option<Box<dyn Entity>> {
+        let archetype = self.archetypes.get(name)?;
+
+        if let Some(flying) = &archetype.flying {
+            let mut entity = FlyingEnemy::new(name, x, y, archetype.hull, archetype.damage, Faction::Monsters);
+            entity.flying.max_altitude = flying.max_altitude;
+            Some(Box::new(entity))
+        } else {
+            Some(Box::new(Enemy::new(
+                name,
+                x,
+                y,
+                archetype.hull,
+                archetype.damage,
+                Faction::Monsters,
+            )))
+        }
+    }
+}
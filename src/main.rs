@@ -52,6 +52,19 @@ class FlyingEnemy extends Enemy {
 // PART 2: Rust's Trait-Based Composition Approach
 // =============================================================================
 
+use std::collections::HashMap;
+
+// These derives auto-generate the delegating trait impls below for any
+// struct with a matching `#[component]` field (see PART 4 and the
+// `entity_derive` crate).
+use entity_derive::{Combatant, Health, Position};
+
+mod content;
+use content::ContentRegistry;
+
+mod world;
+use world::{ai_system, flying_bob_system, movement_system, AiComponent, World};
+
 // Define capabilities as traits instead of base classes
 trait Position {
     fn get_position(&self) -> (f32, f32);
@@ -65,22 +78,136 @@ trait Movable: Position {
     }
 }
 
+// A typed damage value: the mitigation curve differs depending on whether
+// it's resisted by physical or magic resistance (see `Health::apply_damage`).
+#[derive(Debug, Clone, Copy)]
+enum Damage {
+    Physical(f32),
+    Magic(f32),
+}
+
+impl std::fmt::Display for Damage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Damage::Physical(d) => write!(f, "{:.1} physical", d),
+            Damage::Magic(d) => write!(f, "{:.1} magic", d),
+        }
+    }
+}
+
+impl Damage {
+    // The raw magnitude, ignoring its physical/magic type. Used by
+    // `resolve_tick`'s armor-based attrition, which (unlike `apply_damage`)
+    // doesn't distinguish damage types.
+    fn magnitude(&self) -> f32 {
+        match self {
+            Damage::Physical(d) | Damage::Magic(d) => *d,
+        }
+    }
+}
+
 trait Health {
     fn get_health(&self) -> i32;
     fn set_health(&mut self, health: i32);
+    fn get_physical_resistance(&self) -> f32;
+    fn get_magic_resistance(&self) -> f32;
+    // Flat damage reduction applied per tick by `resolve_tick`, distinct
+    // from the percentage-style mitigation `apply_damage` gives resistance.
+    fn armor(&self) -> f32;
     fn is_alive(&self) -> bool {
         self.get_health() > 0
     }
-    fn take_damage(&mut self, damage: i32) {
-        let new_health = self.get_health() - damage;
+    // Raw damage is divided by e^resistance, so each point of resistance
+    // gives smooth diminishing returns without ever granting full immunity.
+    fn apply_damage(&mut self, damage: Damage) {
+        let mitigated = match damage {
+            Damage::Physical(d) => d / self.get_physical_resistance().exp(),
+            Damage::Magic(d) => d / self.get_magic_resistance().exp(),
+        };
+        let new_health = self.get_health() - mitigated.round() as i32;
         self.set_health(new_health.max(0));
     }
 }
 
-trait Combatant: Health {
-    fn get_attack_damage(&self) -> i32;
-    fn attack<T: Health>(&self, target: &mut T) {
-        target.take_damage(self.get_attack_damage());
+// Identifies which faction an entity belongs to, so `Combatant::attack` can
+// tell friend from foe before it lets damage through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Faction {
+    Players,
+    Monsters,
+    Wildlife,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relationship {
+    Hostile,
+    Neutral,
+    Allied,
+}
+
+// Looks up the relationship for an ordered (attacker, target) faction pair,
+// defaulting to `Neutral` for any pair that hasn't been configured.
+#[derive(Debug, Default)]
+struct FactionTable {
+    relationships: HashMap<(Faction, Faction), Relationship>,
+}
+
+impl FactionTable {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn set_relationship(&mut self, attacker: Faction, target: Faction, relationship: Relationship) {
+        self.relationships.insert((attacker, target), relationship);
+    }
+
+    fn relationship(&self, attacker: Faction, target: Faction) -> Relationship {
+        self.relationships
+            .get(&(attacker, target))
+            .copied()
+            .unwrap_or(Relationship::Neutral)
+    }
+}
+
+trait Affiliated {
+    fn faction(&self) -> Faction;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CanAttackFailReason {
+    SameFaction,
+    AlliedFaction,
+    OutOfRange,
+    NotAttackable,
+}
+
+trait Combatant: Health + Affiliated + Position {
+    fn get_attack_damage(&self) -> Damage;
+    fn attack<T: Health + Affiliated + Position>(
+        &self,
+        target: &mut T,
+        factions: &FactionTable,
+        range: f32,
+    ) -> Result<(), CanAttackFailReason> {
+        if !target.is_alive() {
+            return Err(CanAttackFailReason::NotAttackable);
+        }
+        if self.faction() == target.faction() {
+            return Err(CanAttackFailReason::SameFaction);
+        }
+        if factions.relationship(self.faction(), target.faction()) == Relationship::Allied {
+            return Err(CanAttackFailReason::AlliedFaction);
+        }
+
+        let (ax, ay) = self.get_position();
+        let (tx, ty) = target.get_position();
+        let distance = ((ax - tx).powi(2) + (ay - ty).powi(2)).sqrt();
+        if distance > range {
+            return Err(CanAttackFailReason::OutOfRange);
+        }
+
+        target.apply_damage(self.get_attack_damage());
+        Ok(())
     }
 }
 
@@ -96,7 +223,7 @@ trait Drawable {
 // PART 3: Component Structs (Composition Building Blocks)
 // =============================================================================
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 struct PositionComponent {
     x: f32,
     y: f32,
@@ -112,21 +239,30 @@ impl PositionComponent {
 struct HealthComponent {
     current: i32,
     max: i32,
+    physical_resistance: f32,
+    magic_resistance: f32,
+    armor: f32,
 }
 
 impl HealthComponent {
-    fn new(max: i32) -> Self {
-        Self { current: max, max }
+    fn new(max: i32, physical_resistance: f32, magic_resistance: f32, armor: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            physical_resistance,
+            magic_resistance,
+            armor,
+        }
     }
 }
 
 #[derive(Debug, Clone)]
 struct CombatComponent {
-    damage: i32,
+    damage: Damage,
 }
 
 impl CombatComponent {
-    fn new(damage: i32) -> Self {
+    fn new(damage: Damage) -> Self {
         Self { damage }
     }
 }
@@ -150,53 +286,38 @@ impl FlyingComponent {
 // PART 4: Concrete Entities Using Composition
 // =============================================================================
 
-// A basic enemy composed of multiple components
-#[derive(Debug)]
+// A basic enemy composed of multiple components. `Position`, `Health` and
+// `Combatant` are delegated to the `#[component]` fields by derive macros
+// instead of hand-written forwarding impls.
+#[derive(Debug, Position, Health, Combatant)]
 struct Enemy {
     name: String,
+    #[component]
     position: PositionComponent,
+    #[component]
     health: HealthComponent,
+    #[component]
     combat: CombatComponent,
+    faction: Faction,
 }
 
 impl Enemy {
-    fn new(name: impl Into<String>, x: f32, y: f32, health: i32, damage: i32) -> Self {
+    fn new(name: impl Into<String>, x: f32, y: f32, health: i32, damage: f32, faction: Faction) -> Self {
         Self {
             name: name.into(),
             position: PositionComponent::new(x, y),
-            health: HealthComponent::new(health),
-            combat: CombatComponent::new(damage),
+            health: HealthComponent::new(health, 0.1, 0.0, 2.0),
+            combat: CombatComponent::new(Damage::Physical(damage)),
+            faction,
         }
     }
 }
 
-// Implement traits for Enemy by delegating to components
-impl Position for Enemy {
-    fn get_position(&self) -> (f32, f32) {
-        (self.position.x, self.position.y)
-    }
-
-    fn set_position(&mut self, x: f32, y: f32) {
-        self.position.x = x;
-        self.position.y = y;
-    }
-}
-
 impl Movable for Enemy {}
 
-impl Health for Enemy {
-    fn get_health(&self) -> i32 {
-        self.health.current
-    }
-
-    fn set_health(&mut self, health: i32) {
-        self.health.current = health.min(self.health.max);
-    }
-}
-
-impl Combatant for Enemy {
-    fn get_attack_damage(&self) -> i32 {
-        self.combat.damage
+impl Affiliated for Enemy {
+    fn faction(&self) -> Faction {
+        self.faction
     }
 }
 
@@ -215,23 +336,28 @@ impl Drawable for Enemy {
 }
 
 // A flying enemy that composes the same components plus a flying component
-#[derive(Debug)]
+#[derive(Debug, Position, Health, Combatant)]
 struct FlyingEnemy {
     name: String,
+    #[component]
     position: PositionComponent,
+    #[component]
     health: HealthComponent,
+    #[component]
     combat: CombatComponent,
     flying: FlyingComponent,
+    faction: Faction,
 }
 
 impl FlyingEnemy {
-    fn new(name: impl Into<String>, x: f32, y: f32, health: i32, damage: i32) -> Self {
+    fn new(name: impl Into<String>, x: f32, y: f32, health: i32, damage: f32, faction: Faction) -> Self {
         Self {
             name: name.into(),
             position: PositionComponent::new(x, y),
-            health: HealthComponent::new(health),
-            combat: CombatComponent::new(damage),
+            health: HealthComponent::new(health, 0.0, 0.5, 5.0),
+            combat: CombatComponent::new(Damage::Magic(damage)),
             flying: FlyingComponent::new(100.0),
+            faction,
         }
     }
 
@@ -244,32 +370,11 @@ impl FlyingEnemy {
     }
 }
 
-impl Position for FlyingEnemy {
-    fn get_position(&self) -> (f32, f32) {
-        (self.position.x, self.position.y)
-    }
-
-    fn set_position(&mut self, x: f32, y: f32) {
-        self.position.x = x;
-        self.position.y = y;
-    }
-}
-
 impl Movable for FlyingEnemy {}
 
-impl Health for FlyingEnemy {
-    fn get_health(&self) -> i32 {
-        self.health.current
-    }
-
-    fn set_health(&mut self, health: i32) {
-        self.health.current = health.min(self.health.max);
-    }
-}
-
-impl Combatant for FlyingEnemy {
-    fn get_attack_damage(&self) -> i32 {
-        self.combat.damage
+impl Affiliated for FlyingEnemy {
+    fn faction(&self) -> Faction {
+        self.faction
     }
 }
 
@@ -296,13 +401,17 @@ impl Drawable for FlyingEnemy {
 }
 
 // A player that can also fly (easy to add with composition!)
-#[derive(Debug)]
+#[derive(Debug, Position, Health, Combatant)]
 struct Player {
     name: String,
+    #[component]
     position: PositionComponent,
+    #[component]
     health: HealthComponent,
+    #[component]
     combat: CombatComponent,
     flying: Option<FlyingComponent>, // Optional flying capability!
+    faction: Faction,
 }
 
 impl Player {
@@ -310,9 +419,10 @@ impl Player {
         Self {
             name: name.into(),
             position: PositionComponent::new(x, y),
-            health: HealthComponent::new(100),
-            combat: CombatComponent::new(25),
+            health: HealthComponent::new(100, 0.2, 0.2, 3.0),
+            combat: CombatComponent::new(Damage::Physical(25.0)),
             flying: None,
+            faction: Faction::Players,
         }
     }
 
@@ -325,32 +435,11 @@ impl Player {
     }
 }
 
-impl Position for Player {
-    fn get_position(&self) -> (f32, f32) {
-        (self.position.x, self.position.y)
-    }
-
-    fn set_position(&mut self, x: f32, y: f32) {
-        self.position.x = x;
-        self.position.y = y;
-    }
-}
-
 impl Movable for Player {}
 
-impl Health for Player {
-    fn get_health(&self) -> i32 {
-        self.health.current
-    }
-
-    fn set_health(&mut self, health: i32) {
-        self.health.current = health.min(self.health.max);
-    }
-}
-
-impl Combatant for Player {
-    fn get_attack_damage(&self) -> i32 {
-        self.combat.damage
+impl Affiliated for Player {
+    fn faction(&self) -> Faction {
+        self.faction
     }
 }
 
@@ -382,9 +471,12 @@ impl<T: Updatable + Drawable> Entity for T {}
 fn simulate_game_loop() {
     println!("\n=== Game Simulation ===\n");
 
+    let registry =
+        ContentRegistry::load_file("content/entities.toml").expect("failed to load entity content");
+
     let mut entities: Vec<Box<dyn Entity>> = vec![
-        Box::new(Enemy::new("Goblin", 0.0, 0.0, 50, 10)),
-        Box::new(FlyingEnemy::new("Dragon", 100.0, 50.0, 150, 30)),
+        registry.spawn("goblin", 0.0, 0.0).expect("unknown archetype: goblin"),
+        registry.spawn("dragon", 100.0, 50.0).expect("unknown archetype: dragon"),
     ];
 
     // Simulate 3 frames
@@ -398,6 +490,65 @@ fn simulate_game_loop() {
     }
 }
 
+fn demonstrate_ecs() {
+    println!("\n=== ECS Demo ===\n");
+
+    let mut world = World::new();
+
+    let goblin = world.spawn();
+    world.insert_component(goblin, PositionComponent::new(0.0, 0.0));
+    world.insert_component(
+        goblin,
+        AiComponent {
+            forward_speed: 10.0,
+            vertical_speed: 0.0,
+        },
+    );
+
+    let dragon = world.spawn();
+    world.insert_component(dragon, PositionComponent::new(100.0, 50.0));
+    world.insert_component(
+        dragon,
+        AiComponent {
+            forward_speed: 15.0,
+            vertical_speed: 5.0,
+        },
+    );
+    world.insert_component(dragon, FlyingComponent::new(100.0));
+
+    for frame in 1..=3 {
+        ai_system(&mut world);
+        movement_system(&mut world, 0.1);
+        flying_bob_system(&mut world, 0.1);
+
+        let goblin_pos = *world.get_component::<PositionComponent>(goblin).unwrap();
+        println!("Frame {}: goblin at ({:.1}, {:.1})", frame, goblin_pos.x, goblin_pos.y);
+
+        let dragon_pos = *world.get_component::<PositionComponent>(dragon).unwrap();
+        let dragon_altitude = world.get_component::<FlyingComponent>(dragon).unwrap().altitude;
+        println!(
+            "Frame {}: dragon at ({:.1}, {:.1}) altitude {:.1}",
+            frame, dragon_pos.x, dragon_pos.y, dragon_altitude
+        );
+    }
+    println!();
+
+    // No `Option<FlyingComponent>` field to special-case: any entity can
+    // gain or lose flight just by inserting or removing the component.
+    println!("Granting the goblin flight at runtime by inserting a FlyingComponent...");
+    world.insert_component(goblin, FlyingComponent::new(60.0));
+    flying_bob_system(&mut world, 0.1);
+    let goblin_altitude = world.get_component::<FlyingComponent>(goblin).unwrap().altitude;
+    println!("Goblin altitude: {:.1}", goblin_altitude);
+
+    println!("Revoking the goblin's flight...");
+    world.remove_component::<FlyingComponent>(goblin);
+    println!(
+        "Goblin still flying? {}",
+        world.get_component::<FlyingComponent>(goblin).is_some()
+    );
+}
+
 // =============================================================================
 // PART 6: Demonstration of Flexibility
 // =============================================================================
@@ -405,8 +556,13 @@ fn simulate_game_loop() {
 fn demonstrate_combat() {
     println!("\n=== Combat System Demo ===\n");
 
+    let mut factions = FactionTable::new();
+    factions.set_relationship(Faction::Players, Faction::Monsters, Relationship::Hostile);
+    factions.set_relationship(Faction::Monsters, Faction::Players, Relationship::Hostile);
+
     let mut player = Player::new("Alice", 0.0, 0.0);
-    let mut enemy = Enemy::new("Orc", 10.0, 0.0, 30, 15);
+    let mut enemy = Enemy::new("Orc", 10.0, 0.0, 30, 15.0, Faction::Monsters);
+    let dragon = FlyingEnemy::new("Dragon", 20.0, 0.0, 150, 15.0, Faction::Wildlife);
 
     println!("{}", player.draw());
     println!("{}", enemy.draw());
@@ -414,14 +570,67 @@ fn demonstrate_combat() {
 
     // Player attacks enemy
     println!("Player attacks enemy for {} damage!", player.get_attack_damage());
-    player.attack(&mut enemy);
+    if let Err(reason) = player.attack(&mut enemy, &factions, 20.0) {
+        println!("Attack failed: {:?}", reason);
+    }
     println!("{}", enemy.draw());
     println!();
 
     // Enemy attacks back
     println!("Enemy attacks player for {} damage!", enemy.get_attack_damage());
-    enemy.attack(&mut player);
+    if let Err(reason) = enemy.attack(&mut player, &factions, 20.0) {
+        println!("Attack failed: {:?}", reason);
+    }
     println!("{}", player.draw());
+    println!();
+
+    // The dragon is Wildlife, not Monsters, so it's free to attack the orc.
+    // Same raw damage amount, but the attack is magic: against the enemy's
+    // 0.0 magic resistance it lands harder than the enemy's own physical
+    // attack did against the player's 0.2 physical resistance.
+    println!("Dragon breathes {} at enemy!", dragon.get_attack_damage());
+    if let Err(reason) = dragon.attack(&mut enemy, &factions, 20.0) {
+        println!("Attack failed: {:?}", reason);
+    }
+    println!("{}", enemy.draw());
+}
+
+fn demonstrate_factions() {
+    println!("\n=== Faction System Demo ===\n");
+
+    let mut factions = FactionTable::new();
+    factions.set_relationship(Faction::Players, Faction::Monsters, Relationship::Hostile);
+    factions.set_relationship(Faction::Players, Faction::Wildlife, Relationship::Allied);
+
+    let player = Player::new("Alice", 0.0, 0.0);
+    let mut hostile_goblin = Enemy::new("Goblin", 5.0, 0.0, 40, 10.0, Faction::Monsters);
+    let mut goblin_scout = Enemy::new("Goblin Scout", 5.0, 0.0, 40, 10.0, Faction::Monsters);
+    let mut tamed_goblin = Enemy::new("Tamed Goblin", 5.0, 0.0, 40, 10.0, Faction::Wildlife);
+
+    // `attack` checks `relationship(attacker.faction(), target.faction())`,
+    // so it's Players->Wildlife that gates this one: Allied, refused before
+    // any damage is computed.
+    println!("Player attacks the tamed goblin...");
+    match player.attack(&mut tamed_goblin, &factions, 20.0) {
+        Ok(()) => println!("{}", tamed_goblin.draw()),
+        Err(reason) => println!("Attack refused: {:?}", reason),
+    }
+    println!();
+
+    // Players->Monsters is Hostile, so this one lands.
+    println!("Player attacks the hostile goblin...");
+    match player.attack(&mut hostile_goblin, &factions, 20.0) {
+        Ok(()) => println!("{}", hostile_goblin.draw()),
+        Err(reason) => println!("Attack refused: {:?}", reason),
+    }
+    println!();
+
+    // Same faction, always refused regardless of the relationship table.
+    println!("Goblin attacks goblin scout (same faction)...");
+    match hostile_goblin.attack(&mut goblin_scout, &factions, 20.0) {
+        Ok(()) => println!("{}", goblin_scout.draw()),
+        Err(reason) => println!("Attack refused: {:?}", reason),
+    }
 }
 
 fn demonstrate_composition_flexibility() {
@@ -463,10 +672,124 @@ fn move_towards<T: Position + Movable>(entity: &mut T, target_x: f32, target_y:
     }
 }
 
+// The raw attack/armor pair behind one tick of sustained combat, separate
+// from the instant `Combatant::attack` above: armor flatly reduces incoming
+// damage per second instead of the exponential resistance curve.
+struct AttackInformation {
+    attack: f32,
+    armor: f32,
+}
+
+impl AttackInformation {
+    fn damage_per_second(&self) -> f32 {
+        (self.attack - self.armor).max(0.0)
+    }
+}
+
+// What one call to `resolve_tick` did, so callers can show health draining
+// over multiple frames instead of an instant kill.
+struct TickOutcome {
+    damage_dealt: f32,
+    defender_survives: bool,
+}
+
+// Applies one tick of sustained, armor-mitigated damage from `attacker` to
+// `defender` while they're within `range`, instead of the single lump-sum
+// hit `Combatant::attack` deals. Gated by the same faction rules as
+// `Combatant::attack`, so a sustained engagement can't land on an ally.
+fn resolve_tick<A: Combatant, D: Health + Affiliated + Position>(
+    attacker: &A,
+    defender: &mut D,
+    factions: &FactionTable,
+    delta_time: f32,
+    range: f32,
+) -> Result<TickOutcome, CanAttackFailReason> {
+    if !defender.is_alive() {
+        return Err(CanAttackFailReason::NotAttackable);
+    }
+    if attacker.faction() == defender.faction() {
+        return Err(CanAttackFailReason::SameFaction);
+    }
+    if factions.relationship(attacker.faction(), defender.faction()) == Relationship::Allied {
+        return Err(CanAttackFailReason::AlliedFaction);
+    }
+
+    let (ax, ay) = attacker.get_position();
+    let (dx, dy) = defender.get_position();
+    let distance = ((ax - dx).powi(2) + (ay - dy).powi(2)).sqrt();
+    if distance > range {
+        return Err(CanAttackFailReason::OutOfRange);
+    }
+
+    let info = AttackInformation {
+        attack: attacker.get_attack_damage().magnitude(),
+        armor: defender.armor(),
+    };
+    let health_before = defender.get_health();
+    let raw_damage = info.damage_per_second() * delta_time;
+    let new_health = (health_before as f32 - raw_damage).max(0.0);
+    defender.set_health(new_health.round() as i32);
+
+    // Report the HP actually removed, not the un-rounded `raw_damage` float:
+    // `current` is an `i32`, so summing the raw per-tick damage across several
+    // ticks can drift from the real HP delta once rounding accumulates.
+    let damage_dealt = (health_before - defender.get_health()) as f32;
+
+    Ok(TickOutcome {
+        damage_dealt,
+        defender_survives: defender.is_alive(),
+    })
+}
+
+fn demonstrate_tick_combat() {
+    println!("\n=== Tick-Based Attrition Combat Demo ===\n");
+
+    let mut factions = FactionTable::new();
+    factions.set_relationship(Faction::Monsters, Faction::Players, Relationship::Hostile);
+
+    let attacker = Enemy::new("Bandit", 0.0, 0.0, 30, 12.0, Faction::Monsters);
+    let mut defender = Player::new("Dana", 5.0, 0.0);
+
+    let info = AttackInformation {
+        attack: attacker.get_attack_damage().magnitude(),
+        armor: defender.armor(),
+    };
+    println!("{}", defender.draw());
+    println!(
+        "Bandit attack {:.1} vs Dana armor {:.1}: {:.1} damage/sec while in range",
+        attacker.get_attack_damage().magnitude(),
+        defender.armor(),
+        info.damage_per_second(),
+    );
+    println!();
+
+    // Simulate the two staying in contact for several frames instead of a
+    // single `attack()` call; health drains gradually rather than all at once.
+    for frame in 1..=5 {
+        let outcome = match resolve_tick(&attacker, &mut defender, &factions, 0.5, 10.0) {
+            Ok(outcome) => outcome,
+            Err(reason) => {
+                println!("Frame {}: tick refused: {:?}", frame, reason);
+                break;
+            }
+        };
+        println!(
+            "Frame {}: {:.1} damage this tick -> {}",
+            frame,
+            outcome.damage_dealt,
+            defender.draw()
+        );
+        if !outcome.defender_survives {
+            println!("Dana has fallen!");
+            break;
+        }
+    }
+}
+
 fn demonstrate_generic_functions() {
     println!("\n=== Generic Functions Demo ===\n");
 
-    let mut enemy = Enemy::new("Slime", 0.0, 0.0, 20, 5);
+    let mut enemy = Enemy::new("Slime", 0.0, 0.0, 20, 5.0, Faction::Monsters);
     let mut player = Player::new("Charlie", 100.0, 100.0);
 
     println!("Before movement:");
@@ -498,9 +821,12 @@ fn main() {
     println!("â•šâ•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•");
 
     demonstrate_combat();
+    demonstrate_factions();
     simulate_game_loop();
+    demonstrate_ecs();
     demonstrate_composition_flexibility();
     demonstrate_generic_functions();
+    demonstrate_tick_combat();
 
     println!("\n=== Key Takeaways ===");
     println!("âœ“ Rust uses traits instead of inheritance hierarchies");
@@ -0,0 +1,165 @@
+// =============================================================================
+// PART 9: An Alternative — Entity-Component-System
+// =============================================================================
+//
+// PART 4 composes capabilities at *type-definition* time: `Enemy` always has
+// a `CombatComponent`, `Player`'s flight is a permanent `Option<FlyingComponent>`
+// field. A `World` composes them at *runtime* instead: entities are bare
+// integer IDs, each component type lives in its own homogeneous storage, and
+// behavior is expressed as free-standing systems that iterate whichever
+// entities currently have the components a system cares about. Granting or
+// revoking flight becomes `insert_component`/`remove_component::<FlyingComponent>`
+// rather than a dedicated `Option` field on one hand-picked struct.
+
+use std::collections::HashMap;
+
+use crate::{CombatComponent, FlyingComponent, HealthComponent, PositionComponent};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EntityId(u32);
+
+/// A velocity in units per second, applied to `PositionComponent` by
+/// `movement_system` scaled by the frame's `delta_time`.
+#[derive(Debug, Clone, Copy)]
+pub struct VelocityComponent {
+    pub dx: f32,
+    pub dy: f32,
+}
+
+/// Marks an entity as driven by the simple "move in a fixed direction" AI,
+/// read by `ai_system` to (re)compute its `VelocityComponent` each tick.
+#[derive(Debug, Clone, Copy)]
+pub struct AiComponent {
+    pub forward_speed: f32,
+    pub vertical_speed: f32,
+}
+
+/// Implemented once per component type so `World` can offer generic
+/// `insert_component`/`get_component_mut`/`query` methods instead of one
+/// pair of methods per storage.
+pub trait Component: Sized {
+    fn storage(world: &World) -> &HashMap<EntityId, Self>;
+    fn storage_mut(world: &mut World) -> &mut HashMap<EntityId, Self>;
+}
+
+macro_rules! component_storage {
+    ($ty:ty, $field:ident) => {
+        impl Component for $ty {
+            fn storage(world: &World) -> &HashMap<EntityId, Self> {
+                &world.$field
+            }
+
+            fn storage_mut(world: &mut World) -> &mut HashMap<EntityId, Self> {
+                &mut world.$field
+            }
+        }
+    };
+}
+
+component_storage!(PositionComponent, positions);
+component_storage!(HealthComponent, healths);
+component_storage!(CombatComponent, combats);
+component_storage!(FlyingComponent, flyings);
+component_storage!(VelocityComponent, velocities);
+component_storage!(AiComponent, ais);
+
+#[derive(Debug, Default)]
+pub struct World {
+    next_id: u32,
+    positions: HashMap<EntityId, PositionComponent>,
+    healths: HashMap<EntityId, HealthComponent>,
+    combats: HashMap<EntityId, CombatComponent>,
+    flyings: HashMap<EntityId, FlyingComponent>,
+    velocities: HashMap<EntityId, VelocityComponent>,
+    ais: HashMap<EntityId, AiComponent>,
+}
+
+impl World {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocates a fresh `EntityId` with no components attached.
+    pub fn spawn(&mut self) -> EntityId {
+        let id = EntityId(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    pub fn insert_component<C: Component>(&mut self, entity: EntityId, component: C) {
+        C::storage_mut(self).insert(entity, component);
+    }
+
+    pub fn remove_component<C: Component>(&mut self, entity: EntityId) -> Option<C> {
+        C::storage_mut(self).remove(&entity)
+    }
+
+    pub fn get_component<C: Component>(&self, entity: EntityId) -> Option<&C> {
+        C::storage(self).get(&entity)
+    }
+
+    pub fn get_component_mut<C: Component>(&mut self, entity: EntityId) -> Option<&mut C> {
+        C::storage_mut(self).get_mut(&entity)
+    }
+
+    /// Entities that have an `A` component.
+    pub fn query1<'a, A: Component + 'a>(&'a self) -> impl Iterator<Item = EntityId> + 'a {
+        A::storage(self).keys().copied()
+    }
+
+    /// Entities that have both an `A` and a `B` component.
+    pub fn query2<'a, A: Component + 'a, B: Component + 'a>(
+        &'a self,
+    ) -> impl Iterator<Item = EntityId> + 'a {
+        A::storage(self)
+            .keys()
+            .copied()
+            .filter(|id| B::storage(self).contains_key(id))
+    }
+}
+
+/// Recomputes the `VelocityComponent` of every AI-driven entity from its
+/// `AiComponent`. A stand-in for richer AI: today it's a constant heading,
+/// but the seam lets future behavior (chase, flee, patrol) swap in without
+/// touching `movement_system`.
+pub fn ai_system(world: &mut World) {
+    let driven: Vec<EntityId> = world.query2::<AiComponent, PositionComponent>().collect();
+    for entity in driven {
+        let ai = *world.get_component::<AiComponent>(entity).unwrap();
+        world.insert_component(
+            entity,
+            VelocityComponent {
+                dx: ai.forward_speed,
+                dy: ai.vertical_speed,
+            },
+        );
+    }
+}
+
+/// Applies `VelocityComponent` to `PositionComponent` for every entity that
+/// has both, regardless of what gave it a velocity (AI, player input, ...).
+pub fn movement_system(world: &mut World, delta_time: f32) {
+    let moving: Vec<EntityId> = world.query2::<PositionComponent, VelocityComponent>().collect();
+    for entity in moving {
+        let velocity = *world.get_component::<VelocityComponent>(entity).unwrap();
+        let position = world.get_component_mut::<PositionComponent>(entity).unwrap();
+        position.x += velocity.dx * delta_time;
+        position.y += velocity.dy * delta_time;
+    }
+}
+
+/// Bobs every flying entity's altitude between 0 and its max, descending
+/// once above a fixed altitude of 50 and ascending otherwise — the same rule
+/// `FlyingEnemy::update` uses, now expressed over however many entities
+/// have a `FlyingComponent` instead of one hardcoded struct.
+pub fn flying_bob_system(world: &mut World, delta_time: f32) {
+    let flying: Vec<EntityId> = world.query1::<FlyingComponent>().collect();
+    for entity in flying {
+        let flying = world.get_component_mut::<FlyingComponent>(entity).unwrap();
+        if flying.altitude > 50.0 {
+            flying.altitude = (flying.altitude - 20.0 * delta_time).max(0.0);
+        } else {
+            flying.altitude = (flying.altitude + 20.0 * delta_time).min(flying.max_altitude);
+        }
+    }
+}